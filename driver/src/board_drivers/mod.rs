@@ -0,0 +1,5 @@
+pub mod serial;
+pub mod types;
+
+pub use serial::{NeuroSkyParser, PacketParser, SerialDriver};
+pub use types::{AdcConfig, DriverStatus, DriverType};
@@ -0,0 +1,281 @@
+use std::collections::VecDeque;
+use std::io;
+use std::time::Duration;
+
+use serialport::SerialPort;
+
+use super::types::DriverStatus;
+
+/// Turns an incoming byte stream from a serial-framed board into per-channel samples.
+///
+/// Implementations own their own internal buffering, since a device's byte-framed packets rarely
+/// align with individual `read()` calls.
+pub trait PacketParser: Send {
+    /// Number of channels this parser produces samples for.
+    fn channel_count(&self) -> usize;
+
+    /// Feed newly-received bytes; appends one `Vec<i32>` of per-channel values to `out` for each
+    /// fully-decoded, checksum-valid packet. Unused/partial bytes are kept internally.
+    fn feed(&mut self, bytes: &[u8], out: &mut Vec<Vec<i32>>);
+}
+
+/// A generic serial-port board driver: opens a UART/USB serial path, reads bytes as they arrive,
+/// and hands them to a pluggable [`PacketParser`] to turn into samples.
+///
+/// Used for microcontroller frontends (e.g. RP2040/FreeEEG-style boards) and consumer headsets
+/// that speak a byte-framed protocol, as an alternative to a directly-wired ADC driver.
+pub struct SerialDriver {
+    port_name: String,
+    baud: u32,
+    parser: Box<dyn PacketParser>,
+    port: Option<Box<dyn SerialPort>>,
+    status: DriverStatus,
+    read_buf: [u8; 1024],
+}
+
+impl SerialDriver {
+    pub fn new(port_name: impl Into<String>, baud: u32, parser: Box<dyn PacketParser>) -> Self {
+        Self {
+            port_name: port_name.into(),
+            baud,
+            parser,
+            port: None,
+            status: DriverStatus::NotStarted,
+            read_buf: [0; 1024],
+        }
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status
+    }
+
+    /// Open the serial port. On failure, `status` is left as `Error` so callers can retry later
+    /// (e.g. after a hot-plugged device re-enumerates).
+    pub fn connect(&mut self) -> io::Result<()> {
+        match serialport::new(&self.port_name, self.baud)
+            .timeout(Duration::from_millis(50))
+            .open()
+        {
+            Ok(port) => {
+                self.port = Some(port);
+                self.status = DriverStatus::Running;
+                Ok(())
+            }
+            Err(err) => {
+                self.status = DriverStatus::Error;
+                Err(io::Error::new(io::ErrorKind::NotConnected, err.to_string()))
+            }
+        }
+    }
+
+    /// Read whatever bytes are currently available and feed them to the parser, returning any
+    /// fully-decoded sample frames.
+    ///
+    /// A read error (as happens when a device is unplugged mid-stream) drops the port and flips
+    /// `status` to `Error`; the caller should poll `status` and call `connect` again to retry.
+    pub fn poll(&mut self) -> Vec<Vec<i32>> {
+        let mut decoded = Vec::new();
+
+        let Some(port) = self.port.as_mut() else {
+            return decoded;
+        };
+
+        match port.read(&mut self.read_buf) {
+            Ok(0) => {}
+            Ok(n) => self.parser.feed(&self.read_buf[..n], &mut decoded),
+            Err(err) if err.kind() == io::ErrorKind::TimedOut => {}
+            Err(_) => {
+                self.port = None;
+                self.status = DriverStatus::Error;
+            }
+        }
+
+        decoded
+    }
+
+    pub fn disconnect(&mut self) {
+        self.port = None;
+        self.status = DriverStatus::Stopped;
+    }
+}
+
+/// NeuroSky ThinkGear single-channel packet parser.
+///
+/// Frames look like `0xAA 0xAA <PLENGTH> <payload...> <checksum>`, where `checksum` is
+/// `0xFF - (sum of payload bytes & 0xFF)`. This parser extracts the `RAW_VALUE` (code `0x80`,
+/// a big-endian 16-bit signed sample) datapoint from each valid packet and ignores the rest
+/// (signal quality, attention/meditation, etc).
+pub struct NeuroSkyParser {
+    buffer: VecDeque<u8>,
+}
+
+impl NeuroSkyParser {
+    const SYNC_BYTE: u8 = 0xAA;
+    const RAW_VALUE_CODE: u8 = 0x80;
+
+    pub fn new() -> Self {
+        Self { buffer: VecDeque::new() }
+    }
+
+    /// Try to decode one packet from the front of `self.buffer`, returning the raw sample and the
+    /// number of bytes it consumed. Returns `None` if the buffer doesn't yet hold a full packet.
+    fn try_decode_one(&self) -> Option<(Option<i32>, usize)> {
+        let bytes: Vec<u8> = self.buffer.iter().copied().collect();
+
+        let Some(sync_pos) = bytes.windows(2).position(|w| w == [Self::SYNC_BYTE, Self::SYNC_BYTE]) else {
+            // No sync pair anywhere in the buffer. Drop everything except a possible dangling
+            // sync byte at the very end (it could still pair with the next incoming byte), so
+            // noise with no sync pair doesn't accumulate in `self.buffer` forever.
+            return (bytes.len() > 1).then(|| (None, bytes.len() - 1));
+        };
+        if sync_pos > 0 {
+            return Some((None, sync_pos)); // drop leading garbage, resync
+        }
+
+        let plength = *bytes.get(2)? as usize;
+        let payload_start = 3;
+        let payload_end = payload_start + plength;
+        let checksum_pos = payload_end;
+        if bytes.len() <= checksum_pos {
+            return None; // not enough bytes yet
+        }
+
+        let payload = &bytes[payload_start..payload_end];
+        let expected_checksum = !payload.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        let consumed = checksum_pos + 1;
+
+        if bytes[checksum_pos] != expected_checksum {
+            // Bad checksum: drop the first sync byte and try again from the next position.
+            return Some((None, 1));
+        }
+
+        let raw_value = Self::extract_raw_value(payload);
+        Some((raw_value, consumed))
+    }
+
+    fn extract_raw_value(payload: &[u8]) -> Option<i32> {
+        let mut i = 0;
+        while i < payload.len() {
+            let code = payload[i];
+            if code >= Self::RAW_VALUE_CODE {
+                let len = *payload.get(i + 1)? as usize;
+                if code == Self::RAW_VALUE_CODE && len == 2 {
+                    let high = *payload.get(i + 2)?;
+                    let low = *payload.get(i + 3)?;
+                    return Some(i16::from_be_bytes([high, low]) as i32);
+                }
+                i += 2 + len;
+            } else {
+                i += 2; // single-byte codes carry a single value byte
+            }
+        }
+        None
+    }
+}
+
+impl Default for NeuroSkyParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PacketParser for NeuroSkyParser {
+    fn channel_count(&self) -> usize {
+        1
+    }
+
+    fn feed(&mut self, bytes: &[u8], out: &mut Vec<Vec<i32>>) {
+        self.buffer.extend(bytes);
+
+        while let Some((raw_value, consumed)) = self.try_decode_one() {
+            for _ in 0..consumed {
+                self.buffer.pop_front();
+            }
+            if let Some(value) = raw_value {
+                out.push(vec![value]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a well-formed `RAW_VALUE` packet (code `0x80`, 2-byte big-endian payload) for `value`.
+    fn raw_value_packet(value: i16) -> Vec<u8> {
+        let [high, low] = value.to_be_bytes();
+        let payload = vec![NeuroSkyParser::RAW_VALUE_CODE, 2, high, low];
+        let checksum = !payload.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+
+        let mut packet = vec![NeuroSkyParser::SYNC_BYTE, NeuroSkyParser::SYNC_BYTE, payload.len() as u8];
+        packet.extend(payload);
+        packet.push(checksum);
+        packet
+    }
+
+    #[test]
+    fn decodes_a_clean_single_packet() {
+        let mut parser = NeuroSkyParser::new();
+        let mut out = Vec::new();
+
+        parser.feed(&raw_value_packet(1234), &mut out);
+
+        assert_eq!(out, vec![vec![1234]]);
+        assert!(parser.buffer.is_empty());
+    }
+
+    #[test]
+    fn resyncs_one_byte_at_a_time_after_a_bad_checksum() {
+        let mut parser = NeuroSkyParser::new();
+        let mut out = Vec::new();
+
+        let mut packet = raw_value_packet(-500);
+        let checksum_pos = packet.len() - 1;
+        packet[checksum_pos] ^= 0xFF; // corrupt the checksum
+
+        // Append a clean packet after the corrupt one so we can confirm the parser recovers.
+        packet.extend(raw_value_packet(42));
+        parser.feed(&packet, &mut out);
+
+        assert_eq!(out, vec![vec![42]]);
+    }
+
+    #[test]
+    fn decodes_a_packet_split_across_two_feed_calls() {
+        let mut parser = NeuroSkyParser::new();
+        let packet = raw_value_packet(777);
+        let (first_half, second_half) = packet.split_at(packet.len() / 2);
+
+        let mut out = Vec::new();
+        parser.feed(first_half, &mut out);
+        assert!(out.is_empty(), "shouldn't decode anything from a partial packet");
+
+        parser.feed(second_half, &mut out);
+        assert_eq!(out, vec![vec![777]]);
+    }
+
+    #[test]
+    fn drops_leading_garbage_before_the_first_sync_pair() {
+        let mut parser = NeuroSkyParser::new();
+        let mut bytes = vec![0x01, 0x02, 0x03];
+        bytes.extend(raw_value_packet(99));
+
+        let mut out = Vec::new();
+        parser.feed(&bytes, &mut out);
+
+        assert_eq!(out, vec![vec![99]]);
+    }
+
+    #[test]
+    fn buffer_does_not_grow_unboundedly_when_no_sync_pair_is_present() {
+        let mut parser = NeuroSkyParser::new();
+        let mut out = Vec::new();
+
+        // Plenty of noise bytes, none of which form a `0xAA 0xAA` sync pair.
+        parser.feed(&[0x01; 4096], &mut out);
+
+        assert!(out.is_empty());
+        assert!(parser.buffer.len() <= 1, "buffer should be drained down to at most a dangling byte");
+    }
+}
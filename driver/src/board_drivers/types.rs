@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dsp::{DspConfig, PipelineConfig};
+use crate::montage::Montage;
+
+/// Identifies which physical board/driver backend produces samples for an [`AdcConfig`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DriverType {
+    Ads1299,
+    /// A byte-framed protocol over a UART/USB serial path; see `AdcConfig::port`/`AdcConfig::baud`
+    /// and [`crate::board_drivers::SerialDriver`].
+    Serial,
+    Mock,
+}
+
+/// Runtime status of a board driver connection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DriverStatus {
+    NotStarted,
+    Running,
+    Stopped,
+    Error,
+}
+
+/// Configuration describing how to talk to a board and how to interpret the samples it produces.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AdcConfig {
+    pub driver_type: DriverType,
+    pub channels: usize,
+    pub sample_rate: u32,
+    /// ADC gain applied to each channel (e.g. 24x on an ADS1299).
+    pub gain: f32,
+    /// Reference voltage in volts used to convert ADC codes to physical units.
+    pub vref: f32,
+    /// Configuration for the `dsp` band-power stage, including custom band edges.
+    pub dsp: DspConfig,
+    /// Configuration for the `dsp` preprocessing pipeline (filtering, re-referencing, bad-channel
+    /// detection) that runs on each block before the FFT.
+    pub pipeline: PipelineConfig,
+    /// Per-channel electrode positions, required by the `SphericalSplineInterpolation` pipeline
+    /// stage. `None` if the montage isn't known (that stage is then a no-op).
+    pub montage: Option<Montage>,
+    /// Serial device path (e.g. `/dev/ttyUSB0` or `COM3`). Only used when `driver_type` is
+    /// `DriverType::Serial`.
+    pub port: Option<String>,
+    /// Baud rate for the serial connection. Only used when `driver_type` is `DriverType::Serial`.
+    pub baud: Option<u32>,
+}
+
+impl Default for AdcConfig {
+    fn default() -> Self {
+        Self {
+            driver_type: DriverType::Mock,
+            channels: 8,
+            sample_rate: 250,
+            gain: 24.0,
+            vref: 4.5,
+            dsp: DspConfig::default(),
+            pipeline: PipelineConfig::default(),
+            montage: None,
+            port: None,
+            baud: None,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+/// Population variance of `samples` (no Bessel correction: every channel in a block has the same
+/// length, so the correction would scale all variances equally and cancel out of the z-scores).
+fn variance(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    samples.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / samples.len() as f32
+}
+
+/// Excess kurtosis (0 for a Gaussian).
+fn kurtosis(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    let var = variance(samples);
+    let var_sq = var.powi(2);
+    if var_sq <= f32::MIN_POSITIVE {
+        return 0.0;
+    }
+    let fourth_moment = samples.iter().map(|s| (s - mean).powi(4)).sum::<f32>() / samples.len() as f32;
+    fourth_moment / var_sq - 3.0
+}
+
+fn median(values: &mut [f32]) -> f32 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Robust (median/MAD-based) modified z-scores for a set of per-channel statistics.
+fn modified_z_scores(values: &[f32]) -> Vec<f32> {
+    let mut sorted = values.to_vec();
+    let med = median(&mut sorted);
+    let mut abs_dev: Vec<f32> = values.iter().map(|v| (v - med).abs()).collect();
+    let mad = median(&mut abs_dev);
+
+    if mad <= 0.0 {
+        // At least half the values are identical, so MAD is zero; fall back to the mean absolute
+        // deviation (scaled to a standard deviation) so a lone outlier is still scored.
+        let mean_ad = abs_dev.iter().sum::<f32>() / values.len() as f32;
+        if mean_ad <= 0.0 {
+            return vec![0.0; values.len()];
+        }
+        return values.iter().map(|v| (v - med) / (1.253314 * mean_ad)).collect();
+    }
+    values.iter().map(|v| 0.6745 * (v - med) / mad).collect()
+}
+
+/// Flag channels whose variance or kurtosis is a robust outlier (modified z-score beyond
+/// `z_threshold`) relative to the other channels in the block.
+pub fn detect_bad_channels(channels: &[Vec<f32>], z_threshold: f32) -> Vec<usize> {
+    if channels.len() < 3 {
+        // Not enough channels for a meaningful robust statistic.
+        return Vec::new();
+    }
+
+    let variances: Vec<f32> = channels.iter().map(|c| variance(c)).collect();
+    let kurtoses: Vec<f32> = channels.iter().map(|c| kurtosis(c)).collect();
+
+    let variance_z = modified_z_scores(&variances);
+    let kurtosis_z = modified_z_scores(&kurtoses);
+
+    (0..channels.len())
+        .filter(|&ch| variance_z[ch].abs() > z_threshold || kurtosis_z[ch].abs() > z_threshold)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_few_channels_flags_nothing() {
+        let channels = vec![vec![0.0, 1.0, 2.0], vec![100.0, 100.0, 100.0]];
+        assert!(detect_bad_channels(&channels, 3.5).is_empty());
+    }
+
+    #[test]
+    fn flags_a_channel_with_wildly_higher_variance() {
+        let quiet = || vec![0.0_f32, 0.1, -0.1, 0.05, -0.05];
+        let mut channels = vec![quiet(), quiet(), quiet(), quiet()];
+        channels.push(vec![10.0, -10.0, 10.0, -10.0, 10.0]); // far higher variance than the rest
+
+        let bad = detect_bad_channels(&channels, 3.5);
+
+        assert_eq!(bad, vec![4]);
+    }
+
+    #[test]
+    fn uniform_channels_flag_nothing() {
+        let channels: Vec<Vec<f32>> = (0..5).map(|_| vec![1.0, 2.0, 3.0, 4.0]).collect();
+        assert!(detect_bad_channels(&channels, 3.5).is_empty());
+    }
+}
@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ProcessedData;
+
+/// A named frequency band as a half-open interval `[low_hz, high_hz)`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Band {
+    pub low_hz: f32,
+    pub high_hz: f32,
+}
+
+/// Absolute (and, when enabled, relative) power in each canonical EEG band for one channel.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BandPowers {
+    pub delta: f32,
+    pub theta: f32,
+    pub alpha: f32,
+    pub beta: f32,
+    pub gamma: f32,
+    /// Each band's absolute power divided by the total power across all five bands.
+    pub delta_rel: Option<f32>,
+    pub theta_rel: Option<f32>,
+    pub alpha_rel: Option<f32>,
+    pub beta_rel: Option<f32>,
+    pub gamma_rel: Option<f32>,
+}
+
+/// Band edges and options for the band-power stage. Held on [`crate::AdcConfig`] so callers can
+/// redefine the canonical delta/theta/alpha/beta/gamma ranges.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DspConfig {
+    pub delta: Band,
+    pub theta: Band,
+    pub alpha: Band,
+    pub beta: Band,
+    pub gamma: Band,
+    /// Whether to also compute each band's power relative to the total power across all bands.
+    pub compute_relative_power: bool,
+}
+
+impl Default for DspConfig {
+    fn default() -> Self {
+        Self {
+            delta: Band { low_hz: 0.5, high_hz: 4.0 },
+            theta: Band { low_hz: 4.0, high_hz: 8.0 },
+            alpha: Band { low_hz: 8.0, high_hz: 13.0 },
+            beta: Band { low_hz: 13.0, high_hz: 30.0 },
+            gamma: Band { low_hz: 30.0, high_hz: 100.0 },
+            compute_relative_power: true,
+        }
+    }
+}
+
+/// Trapezoidally integrate `power_spectrum` over the bins whose frequency falls within
+/// `[band.low_hz, band.high_hz)`.
+fn integrate_band(power_spectrum: &[f32], frequency_bins: &[f32], band: Band) -> f32 {
+    let mut in_band: Vec<(f32, f32)> = frequency_bins
+        .iter()
+        .zip(power_spectrum.iter())
+        .filter(|(&f, _)| f >= band.low_hz && f < band.high_hz)
+        .map(|(&f, &p)| (f, p))
+        .collect();
+
+    if in_band.len() < 2 {
+        return in_band.iter().map(|(_, p)| *p).sum();
+    }
+
+    in_band.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    in_band
+        .windows(2)
+        .map(|pair| {
+            let (f0, p0) = pair[0];
+            let (f1, p1) = pair[1];
+            0.5 * (p0 + p1) * (f1 - f0)
+        })
+        .sum()
+}
+
+/// Compute per-channel band powers from a block's `power_spectrums`/`frequency_bins`, returning
+/// `None` if the FFT stage hasn't populated them.
+pub fn compute_band_powers(data: &ProcessedData, config: &DspConfig) -> Option<Vec<BandPowers>> {
+    let power_spectrums = data.power_spectrums.as_ref()?;
+    let frequency_bins = data.frequency_bins.as_ref()?;
+
+    Some(
+        power_spectrums
+            .iter()
+            .zip(frequency_bins.iter())
+            .map(|(power_spectrum, freqs)| {
+                let delta = integrate_band(power_spectrum, freqs, config.delta);
+                let theta = integrate_band(power_spectrum, freqs, config.theta);
+                let alpha = integrate_band(power_spectrum, freqs, config.alpha);
+                let beta = integrate_band(power_spectrum, freqs, config.beta);
+                let gamma = integrate_band(power_spectrum, freqs, config.gamma);
+                let total = delta + theta + alpha + beta + gamma;
+
+                let relative = |band: f32| -> Option<f32> {
+                    (config.compute_relative_power && total > 0.0).then(|| band / total)
+                };
+
+                BandPowers {
+                    delta,
+                    theta,
+                    alpha,
+                    beta,
+                    gamma,
+                    delta_rel: relative(delta),
+                    theta_rel: relative(theta),
+                    alpha_rel: relative(alpha),
+                    beta_rel: relative(beta),
+                    gamma_rel: relative(gamma),
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integrate_band_trapezoidally_sums_power_within_the_band() {
+        // Two in-band bins at 1 uV^2 and 3 uV^2, 1 Hz apart: trapezoid area = 0.5 * (1+3) * 1 = 2.
+        let power_spectrum = vec![1.0, 3.0, 100.0];
+        let frequency_bins = vec![1.0, 2.0, 20.0]; // the third bin is outside [0, 4)
+
+        let area = integrate_band(&power_spectrum, &frequency_bins, Band { low_hz: 0.0, high_hz: 4.0 });
+
+        assert!((area - 2.0).abs() < 1e-6, "got {area}");
+    }
+
+    #[test]
+    fn integrate_band_with_a_single_in_band_bin_returns_its_raw_power() {
+        let power_spectrum = vec![5.0, 100.0];
+        let frequency_bins = vec![2.0, 20.0];
+
+        let area = integrate_band(&power_spectrum, &frequency_bins, Band { low_hz: 0.0, high_hz: 4.0 });
+
+        assert_eq!(area, 5.0);
+    }
+
+    #[test]
+    fn compute_band_powers_returns_none_without_a_fft_stage() {
+        let data = ProcessedData::default();
+        let config = DspConfig::default();
+
+        assert!(compute_band_powers(&data, &config).is_none());
+    }
+
+    #[test]
+    fn compute_band_powers_computes_relative_power_fractions() {
+        let config = DspConfig {
+            delta: Band { low_hz: 0.0, high_hz: 2.0 },
+            theta: Band { low_hz: 2.0, high_hz: 4.0 },
+            alpha: Band { low_hz: 100.0, high_hz: 200.0 }, // empty: no bins fall in this range
+            beta: Band { low_hz: 100.0, high_hz: 200.0 },
+            gamma: Band { low_hz: 100.0, high_hz: 200.0 },
+            compute_relative_power: true,
+        };
+        let data = ProcessedData {
+            power_spectrums: Some(vec![vec![2.0, 2.0]]),
+            frequency_bins: Some(vec![vec![1.0, 3.0]]),
+            ..Default::default()
+        };
+
+        let powers = compute_band_powers(&data, &config).expect("fft stage populated");
+
+        assert_eq!(powers.len(), 1);
+        assert_eq!(powers[0].delta, 2.0);
+        assert_eq!(powers[0].theta, 2.0);
+        assert_eq!(powers[0].delta_rel, Some(0.5));
+        assert_eq!(powers[0].theta_rel, Some(0.5));
+    }
+}
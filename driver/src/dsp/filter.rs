@@ -0,0 +1,176 @@
+/// Coefficients for a direct-form-II-transposed biquad section.
+#[derive(Clone, Copy, Debug)]
+struct BiquadCoeffs {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+}
+
+/// Running state (`z1`/`z2`) for a single biquad IIR section, decoupled from its coefficients so
+/// the same state can be carried across calls even though coefficients are cheap to recompute
+/// from config each time.
+#[derive(Clone, Copy, Debug, Default)]
+struct BiquadState {
+    z1: f32,
+    z2: f32,
+}
+
+impl BiquadState {
+    fn process(&mut self, coeffs: &BiquadCoeffs, input: f32) -> f32 {
+        let out = coeffs.b0 * input + self.z1;
+        self.z1 = coeffs.b1 * input - coeffs.a1 * out + self.z2;
+        self.z2 = coeffs.b2 * input - coeffs.a2 * out;
+        out
+    }
+
+    fn process_slice(&mut self, coeffs: &BiquadCoeffs, samples: &mut [f32]) {
+        for sample in samples {
+            *sample = self.process(coeffs, *sample);
+        }
+    }
+}
+
+/// RBJ Audio EQ Cookbook 2nd-order Butterworth high-pass.
+fn highpass_coeffs(cutoff_hz: f32, sample_rate: f32, q: f32) -> BiquadCoeffs {
+    let omega = 2.0 * std::f32::consts::PI * cutoff_hz / sample_rate;
+    let (sin_w, cos_w) = omega.sin_cos();
+    let alpha = sin_w / (2.0 * q);
+
+    let b0 = (1.0 + cos_w) / 2.0;
+    let b1 = -(1.0 + cos_w);
+    let b2 = (1.0 + cos_w) / 2.0;
+    let a0 = 1.0 + alpha;
+    let a1 = -2.0 * cos_w;
+    let a2 = 1.0 - alpha;
+
+    BiquadCoeffs { b0: b0 / a0, b1: b1 / a0, b2: b2 / a0, a1: a1 / a0, a2: a2 / a0 }
+}
+
+/// RBJ Audio EQ Cookbook 2nd-order Butterworth low-pass.
+fn lowpass_coeffs(cutoff_hz: f32, sample_rate: f32, q: f32) -> BiquadCoeffs {
+    let omega = 2.0 * std::f32::consts::PI * cutoff_hz / sample_rate;
+    let (sin_w, cos_w) = omega.sin_cos();
+    let alpha = sin_w / (2.0 * q);
+
+    let b0 = (1.0 - cos_w) / 2.0;
+    let b1 = 1.0 - cos_w;
+    let b2 = (1.0 - cos_w) / 2.0;
+    let a0 = 1.0 + alpha;
+    let a1 = -2.0 * cos_w;
+    let a2 = 1.0 - alpha;
+
+    BiquadCoeffs { b0: b0 / a0, b1: b1 / a0, b2: b2 / a0, a1: a1 / a0, a2: a2 / a0 }
+}
+
+/// RBJ Audio EQ Cookbook notch filter.
+fn notch_coeffs(freq_hz: f32, sample_rate: f32, q: f32) -> BiquadCoeffs {
+    let omega = 2.0 * std::f32::consts::PI * freq_hz / sample_rate;
+    let (sin_w, cos_w) = omega.sin_cos();
+    let alpha = sin_w / (2.0 * q);
+
+    let b0 = 1.0;
+    let b1 = -2.0 * cos_w;
+    let b2 = 1.0;
+    let a0 = 1.0 + alpha;
+    let a1 = -2.0 * cos_w;
+    let a2 = 1.0 - alpha;
+
+    BiquadCoeffs { b0: b0 / a0, b1: b1 / a0, b2: b2 / a0, a1: a1 / a0, a2: a2 / a0 }
+}
+
+const BUTTERWORTH_Q: f32 = std::f32::consts::FRAC_1_SQRT_2;
+const NOTCH_Q: f32 = 30.0;
+
+/// Persistent high-pass/low-pass/notch biquad state for one channel. Kept separate from the
+/// coefficients (which are cheap to recompute from config on every call) so the filter's
+/// transient response carries across block boundaries instead of resetting to zero every block.
+#[derive(Clone, Copy, Debug, Default)]
+struct ChannelFilterState {
+    highpass: BiquadState,
+    lowpass: BiquadState,
+    notch: BiquadState,
+}
+
+/// Owns one [`ChannelFilterState`] per channel so `bandpass_notch` can be applied repeatedly,
+/// block after block, without losing the filters' internal state in between. Must live as long as
+/// the streaming session does (e.g. on `EegSystem`), not be recreated per block.
+#[derive(Clone, Debug, Default)]
+pub struct FilterBank {
+    channels: Vec<ChannelFilterState>,
+}
+
+impl FilterBank {
+    pub fn new(num_channels: usize) -> Self {
+        Self { channels: vec![ChannelFilterState::default(); num_channels] }
+    }
+
+    /// Apply a `[low_hz, high_hz]` bandpass (cascaded high-pass then low-pass) followed by a notch
+    /// at `notch_hz`, in place, to every channel, reusing and updating each channel's persistent
+    /// filter state. Channels beyond the bank's current size get fresh (zeroed) state.
+    pub fn bandpass_notch(
+        &mut self,
+        channels: &mut [Vec<f32>],
+        sample_rate: f32,
+        low_hz: f32,
+        high_hz: f32,
+        notch_hz: f32,
+    ) {
+        if self.channels.len() != channels.len() {
+            self.channels.resize(channels.len(), ChannelFilterState::default());
+        }
+
+        let highpass = highpass_coeffs(low_hz, sample_rate, BUTTERWORTH_Q);
+        let lowpass = lowpass_coeffs(high_hz, sample_rate, BUTTERWORTH_Q);
+        let notch = (notch_hz > 0.0).then(|| notch_coeffs(notch_hz, sample_rate, NOTCH_Q));
+
+        for (state, samples) in self.channels.iter_mut().zip(channels.iter_mut()) {
+            state.highpass.process_slice(&highpass, samples);
+            state.lowpass.process_slice(&lowpass, samples);
+            if let Some(notch) = &notch {
+                state.notch.process_slice(notch, samples);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_persists_across_calls_unlike_a_stateless_filter() {
+        // Feed the same two-sample block twice. With persistent state the second call continues
+        // from the first call's z1/z2, so it produces different output than the first call did;
+        // a stateless (reset-every-call) implementation would produce identical output both times.
+        let mut bank = FilterBank::new(1);
+        let mut first = vec![vec![1.0_f32, 1.0]];
+        let mut second = vec![vec![1.0_f32, 1.0]];
+
+        bank.bandpass_notch(&mut first, 256.0, 1.0, 45.0, 60.0);
+        bank.bandpass_notch(&mut second, 256.0, 1.0, 45.0, 60.0);
+
+        assert_ne!(first[0], second[0]);
+    }
+
+    #[test]
+    fn new_bank_has_zeroed_initial_state() {
+        // A fresh bank's first call should match what a from-scratch stateless filter would do:
+        // zero initial conditions.
+        let mut bank = FilterBank::new(1);
+        let mut samples = vec![vec![0.0_f32, 0.5, -0.5, 1.0]];
+        let original = samples.clone();
+
+        bank.bandpass_notch(&mut samples, 256.0, 1.0, 45.0, 60.0);
+
+        assert_ne!(samples, original);
+
+        let mut expected = original[0].clone();
+        let mut fresh = ChannelFilterState::default();
+        fresh.highpass.process_slice(&highpass_coeffs(1.0, 256.0, BUTTERWORTH_Q), &mut expected);
+        fresh.lowpass.process_slice(&lowpass_coeffs(45.0, 256.0, BUTTERWORTH_Q), &mut expected);
+        fresh.notch.process_slice(&notch_coeffs(60.0, 256.0, NOTCH_Q), &mut expected);
+        assert_eq!(samples[0], expected);
+    }
+}
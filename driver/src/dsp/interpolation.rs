@@ -0,0 +1,163 @@
+use nalgebra::{DMatrix, DVector};
+
+use crate::montage::Montage;
+
+/// Truncation order for the Legendre series in `g`. Perrin et al. suggest 7-50 is sufficient.
+const L_MAX: usize = 50;
+/// Spline order `m` from Perrin et al. (1989).
+const SPLINE_ORDER: i32 = 4;
+/// Small ridge term added to the diagonal of `G` for numerical stability.
+const REGULARIZATION: f64 = 1e-5;
+
+/// Legendre polynomials `P_0..=P_{l_max}` evaluated at `x`, via the standard recurrence.
+fn legendre_series(x: f64, l_max: usize) -> Vec<f64> {
+    let mut p = vec![0.0; l_max + 1];
+    p[0] = 1.0;
+    if l_max >= 1 {
+        p[1] = x;
+    }
+    for n in 2..=l_max {
+        let n_f = n as f64;
+        p[n] = ((2.0 * n_f - 1.0) * x * p[n - 1] - (n_f - 1.0) * p[n - 2]) / n_f;
+    }
+    p
+}
+
+/// Perrin et al.'s `g(x) = 1/(4*pi) * sum_n (2n+1) / (n(n+1))^m * P_n(x)`.
+fn g_function(cos_gamma: f64) -> f64 {
+    let p = legendre_series(cos_gamma, L_MAX);
+    let sum: f64 = (1..=L_MAX)
+        .map(|n| {
+            let n_f = n as f64;
+            (2.0 * n_f + 1.0) / (n_f * (n_f + 1.0)).powi(SPLINE_ORDER) * p[n]
+        })
+        .sum();
+    sum / (4.0 * std::f64::consts::PI)
+}
+
+fn cos_angle(a: (f32, f32, f32), b: (f32, f32, f32)) -> f64 {
+    (a.0 as f64 * b.0 as f64 + a.1 as f64 * b.1 as f64 + a.2 as f64 * b.2 as f64).clamp(-1.0, 1.0)
+}
+
+/// Reconstruct the `bad_channels` rows of `channels` from the good channels via Perrin-style
+/// spherical spline interpolation, using `montage` for electrode positions. `channels[ch]` and
+/// `montage.electrodes[ch]` must correspond to the same physical channel.
+///
+/// Silently leaves `channels` untouched if there's no montage entry per channel, or fewer than
+/// three good electrodes to fit a spline from.
+pub fn spherical_spline_interpolate(channels: &mut [Vec<f32>], montage: &Montage, bad_channels: &[usize]) {
+    if bad_channels.is_empty() || montage.electrodes.len() != channels.len() {
+        return;
+    }
+
+    let good: Vec<usize> = (0..channels.len()).filter(|ch| !bad_channels.contains(ch)).collect();
+    if good.len() < 3 {
+        return;
+    }
+
+    let good_unit: Vec<(f32, f32, f32)> = good.iter().map(|&ch| montage.electrodes[ch].unit()).collect();
+
+    // G is geometry-only (doesn't depend on sample values), so it's built and inverted once per
+    // block and reused for every bad channel and every sample.
+    let n = good.len();
+    let mut g_matrix = DMatrix::<f64>::zeros(n + 1, n + 1);
+    for i in 0..n {
+        for j in 0..n {
+            let g = g_function(cos_angle(good_unit[i], good_unit[j]));
+            g_matrix[(i, j)] = g + if i == j { REGULARIZATION } else { 0.0 };
+        }
+        g_matrix[(i, n)] = 1.0;
+        g_matrix[(n, i)] = 1.0;
+    }
+
+    let Some(g_inverse) = g_matrix.lu().try_inverse() else {
+        return; // singular (e.g. duplicate electrode positions); skip this block
+    };
+
+    let num_samples = channels[good[0]].len();
+
+    for &bad_ch in bad_channels {
+        if bad_ch >= channels.len() {
+            continue;
+        }
+        let bad_unit = montage.electrodes[bad_ch].unit();
+        let g_bad: Vec<f64> = good_unit.iter().map(|&gu| g_function(cos_angle(bad_unit, gu))).collect();
+
+        let mut interpolated = vec![0.0f32; num_samples];
+        for t in 0..num_samples {
+            let mut rhs = DVector::<f64>::zeros(n + 1);
+            for (i, &ch) in good.iter().enumerate() {
+                rhs[i] = channels[ch][t] as f64;
+            }
+            let weights = &g_inverse * rhs;
+            let c0 = weights[n];
+            let value: f64 = (0..n).map(|i| weights[i] * g_bad[i]).sum::<f64>() + c0;
+            interpolated[t] = value as f32;
+        }
+        channels[bad_ch] = interpolated;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::montage::ElectrodeLocation;
+
+    fn electrode(label: &str, x: f32, y: f32, z: f32) -> ElectrodeLocation {
+        ElectrodeLocation { label: label.to_string(), x, y, z }
+    }
+
+    #[test]
+    fn constant_field_reconstructs_exactly_at_bad_electrode() {
+        // A spatially constant potential is a trivial case of any spherical spline (all weights
+        // zero, constant term equal to the shared value), so this isolates whether the linear
+        // solve itself is wired up correctly.
+        let montage = Montage {
+            electrodes: vec![
+                electrode("Fp1", 1.0, 0.0, 0.0),
+                electrode("Fp2", 0.0, 1.0, 0.0),
+                electrode("Cz", 0.0, 0.0, 1.0),
+                electrode("O1", -1.0, 0.0, 0.0),
+                electrode("O2", 0.0, -1.0, 0.0),
+                electrode("Bad", 0.0, 0.0, -1.0),
+            ],
+        };
+
+        let constant_value = 0.00005_f32;
+        let mut channels: Vec<Vec<f32>> = (0..6).map(|_| vec![constant_value; 3]).collect();
+        channels[5] = vec![0.0; 3];
+
+        spherical_spline_interpolate(&mut channels, &montage, &[5]);
+
+        for &sample in &channels[5] {
+            assert!((sample - constant_value).abs() < 1e-6, "got {sample}, expected {constant_value}");
+        }
+    }
+
+    #[test]
+    fn no_bad_channels_leaves_data_untouched() {
+        let montage = Montage {
+            electrodes: vec![electrode("A", 1.0, 0.0, 0.0), electrode("B", 0.0, 1.0, 0.0)],
+        };
+        let mut channels = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+        let original = channels.clone();
+
+        spherical_spline_interpolate(&mut channels, &montage, &[]);
+
+        assert_eq!(channels, original);
+    }
+
+    #[test]
+    fn too_few_good_electrodes_leaves_bad_channel_untouched() {
+        // Only two electrodes total: can't fit a spline (need >= 3 good), so the bad channel's
+        // placeholder data must be left alone rather than producing garbage.
+        let montage = Montage {
+            electrodes: vec![electrode("A", 1.0, 0.0, 0.0), electrode("Bad", 0.0, 1.0, 0.0)],
+        };
+        let mut channels = vec![vec![1.0, 2.0], vec![9.0, 9.0]];
+
+        spherical_spline_interpolate(&mut channels, &montage, &[1]);
+
+        assert_eq!(channels[1], vec![9.0, 9.0]);
+    }
+}
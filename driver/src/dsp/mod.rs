@@ -0,0 +1,13 @@
+mod bad_channels;
+mod bands;
+mod filter;
+mod interpolation;
+mod pipeline;
+mod reference;
+
+pub use bad_channels::detect_bad_channels;
+pub use bands::{compute_band_powers, Band, BandPowers, DspConfig};
+pub use filter::FilterBank;
+pub use interpolation::spherical_spline_interpolate;
+pub use pipeline::{run_pipeline, PipelineConfig, PipelineStage};
+pub use reference::common_average_reference;
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::montage::Montage;
+
+use super::bad_channels::detect_bad_channels;
+use super::filter::FilterBank;
+use super::interpolation::spherical_spline_interpolate;
+use super::reference::common_average_reference;
+
+/// A single preprocessing stage that can be toggled and reordered via [`PipelineConfig`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PipelineStage {
+    BandpassNotch,
+    CommonAverageReference,
+    BadChannelDetection,
+    /// Reconstruct flagged bad channels from good ones; requires `AdcConfig::montage` to be set.
+    SphericalSplineInterpolation,
+}
+
+/// Configuration for the preprocessing pipeline that runs on each block's
+/// `processed_voltage_samples` before the FFT stage.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PipelineConfig {
+    /// Stages to run, in order. Leave a stage out to disable it.
+    ///
+    /// Both `CommonAverageReference` and `SphericalSplineInterpolation` read the accumulated
+    /// `bad_channels` list, which is empty until a `BadChannelDetection` stage has run.
+    /// `CommonAverageReference` ordered before detection references against every channel,
+    /// bad ones included. `SphericalSplineInterpolation` silently no-ops whenever `bad_channels`
+    /// is empty, so ordering it first (or alone) reconstructs nothing.
+    pub stages: Vec<PipelineStage>,
+    pub bandpass_low_hz: f32,
+    pub bandpass_high_hz: f32,
+    /// Mains notch frequency (50 or 60 Hz); 0 disables the notch.
+    pub notch_hz: f32,
+    /// Modified z-score threshold (on variance or kurtosis) beyond which a channel is flagged bad.
+    pub bad_channel_z_threshold: f32,
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            stages: vec![
+                PipelineStage::BandpassNotch,
+                PipelineStage::BadChannelDetection,
+                PipelineStage::CommonAverageReference,
+            ],
+            bandpass_low_hz: 1.0,
+            bandpass_high_hz: 45.0,
+            notch_hz: 60.0,
+            bad_channel_z_threshold: 3.5,
+        }
+    }
+}
+
+/// Run the configured stages over `channels` in place, returning the indices of any channels
+/// flagged bad by a `BadChannelDetection` stage (empty if that stage isn't enabled).
+///
+/// `montage` is only consulted by the `SphericalSplineInterpolation` stage; pass `None` if no
+/// montage is configured (that stage is then a no-op). `filter_bank` must be owned by the caller
+/// and reused across blocks: recreating it every call would reset the bandpass/notch filters'
+/// internal state and reintroduce a transient at every block boundary.
+pub fn run_pipeline(
+    channels: &mut [Vec<f32>],
+    sample_rate: f32,
+    config: &PipelineConfig,
+    montage: Option<&Montage>,
+    filter_bank: &mut FilterBank,
+) -> Vec<usize> {
+    let mut bad_channels = Vec::new();
+
+    for stage in &config.stages {
+        match stage {
+            PipelineStage::BandpassNotch => {
+                filter_bank.bandpass_notch(
+                    channels,
+                    sample_rate,
+                    config.bandpass_low_hz,
+                    config.bandpass_high_hz,
+                    config.notch_hz,
+                );
+            }
+            PipelineStage::CommonAverageReference => {
+                // Always `Some(..)`, even when this resolves to every channel (no detection stage
+                // has run yet): an explicitly empty list (every channel flagged bad) must not be
+                // confused with "no exclusion configured" and fall back to referencing against all
+                // (bad) channels.
+                let good: Vec<usize> = (0..channels.len()).filter(|ch| !bad_channels.contains(ch)).collect();
+                common_average_reference(channels, Some(&good));
+            }
+            PipelineStage::BadChannelDetection => {
+                bad_channels = detect_bad_channels(channels, config.bad_channel_z_threshold);
+            }
+            PipelineStage::SphericalSplineInterpolation => {
+                if let Some(montage) = montage {
+                    spherical_spline_interpolate(channels, montage, &bad_channels);
+                }
+            }
+        }
+    }
+
+    bad_channels
+}
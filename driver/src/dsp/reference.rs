@@ -0,0 +1,68 @@
+/// Common-average re-reference: subtract, from every sample, the per-sample mean across
+/// `good_channels`.
+///
+/// `good_channels: None` means "no channel exclusion configured", so every channel is used.
+/// `good_channels: Some(&[])` is distinct from that: it means a filter *was* applied and found
+/// zero good channels (e.g. every channel got flagged bad), so there's nothing safe to reference
+/// against; this is a no-op rather than falling back to referencing against all (bad) channels.
+pub fn common_average_reference(channels: &mut [Vec<f32>], good_channels: Option<&[usize]>) {
+    if channels.is_empty() {
+        return;
+    }
+
+    let good: Vec<usize> = match good_channels {
+        None => (0..channels.len()).collect(),
+        Some(good) if good.is_empty() => return,
+        Some(good) => good.to_vec(),
+    };
+
+    let num_samples = channels[0].len();
+    for sample_idx in 0..num_samples {
+        let sum: f32 = good
+            .iter()
+            .filter_map(|&ch| channels.get(ch).and_then(|c| c.get(sample_idx)))
+            .sum();
+        let mean = sum / good.len() as f32;
+
+        for channel in channels.iter_mut() {
+            if let Some(sample) = channel.get_mut(sample_idx) {
+                *sample -= mean;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn none_references_against_every_channel() {
+        let mut channels = vec![vec![2.0, 4.0], vec![4.0, 8.0]];
+
+        common_average_reference(&mut channels, None);
+
+        // Per-sample means are 3.0 and 6.0.
+        assert_eq!(channels, vec![vec![-1.0, -2.0], vec![1.0, 2.0]]);
+    }
+
+    #[test]
+    fn some_with_explicit_good_channels_excludes_the_rest() {
+        let mut channels = vec![vec![2.0], vec![4.0], vec![100.0]]; // channel 2 is excluded (bad)
+
+        common_average_reference(&mut channels, Some(&[0, 1]));
+
+        // Mean of good channels (2.0, 4.0) is 3.0; channel 2 is still referenced against it too.
+        assert_eq!(channels, vec![vec![-1.0], vec![1.0], vec![97.0]]);
+    }
+
+    #[test]
+    fn some_empty_is_a_no_op_rather_than_falling_back_to_all_channels() {
+        let mut channels = vec![vec![2.0, 4.0], vec![6.0, 8.0]];
+        let original = channels.clone();
+
+        common_average_reference(&mut channels, Some(&[]));
+
+        assert_eq!(channels, original);
+    }
+}
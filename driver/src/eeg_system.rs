@@ -0,0 +1,216 @@
+use std::io;
+use std::path::Path;
+
+use rustfft::{num_complex::Complex, FftPlanner};
+
+use crate::board_drivers::{AdcConfig, DriverStatus};
+use crate::dsp;
+use crate::events::{self, EpochConfig, EventMarker, Epochs};
+use crate::recording::{Recorder, RecordingFormat};
+use crate::ProcessedData;
+
+/// How much continuous history `EegSystem` keeps for epoching, by default: long enough to cover
+/// a 2 s pre-event baseline plus a generous margin for post-event windows.
+const DEFAULT_HISTORY_RETENTION_SECS: f32 = 30.0;
+
+/// Owns a board driver connection and turns its raw sample stream into [`ProcessedData`] blocks.
+pub struct EegSystem {
+    config: AdcConfig,
+    status: DriverStatus,
+    fft_planner: FftPlanner<f32>,
+    recorder: Option<Recorder>,
+    /// Absolute index of the next sample this system will process.
+    total_samples: u64,
+    /// Continuous per-channel history of processed voltage samples, for epoching. `history[ch][0]`
+    /// is the sample at absolute index `history_start_sample`. Trimmed to `history_retention_samples`
+    /// after every block so memory use stays bounded during long-running acquisitions.
+    history: Vec<Vec<f32>>,
+    history_start_sample: u64,
+    history_retention_samples: u64,
+    events: Vec<EventMarker>,
+    /// Persistent bandpass/notch filter state, one per channel. Owned here (rather than created
+    /// fresh inside `run_pipeline`) so the filters' internal state carries across `process_block`
+    /// calls instead of resetting every block.
+    filter_bank: dsp::FilterBank,
+}
+
+impl EegSystem {
+    pub fn new(config: AdcConfig) -> Self {
+        let channels = config.channels;
+        let history_retention_samples =
+            (config.sample_rate as f32 * DEFAULT_HISTORY_RETENTION_SECS).round() as u64;
+        Self {
+            config,
+            status: DriverStatus::NotStarted,
+            fft_planner: FftPlanner::new(),
+            recorder: None,
+            total_samples: 0,
+            history: vec![Vec::new(); channels],
+            history_start_sample: 0,
+            history_retention_samples,
+            events: Vec::new(),
+            filter_bank: dsp::FilterBank::new(channels),
+        }
+    }
+
+    /// Override how many samples of history are retained for epoching (default: 30 s worth at
+    /// `AdcConfig::sample_rate`). Set this to cover the largest `pre_ms`/`post_ms` window any
+    /// caller will pass to `extract_epochs`.
+    pub fn set_history_retention_samples(&mut self, samples: u64) {
+        self.history_retention_samples = samples;
+    }
+
+    /// Record a timestamped marker at the current position in the sample stream (i.e. at the
+    /// start of the next block passed to `process_block`).
+    pub fn push_event(&mut self, code: u32, label: impl Into<String>) {
+        self.events.push(EventMarker { sample_index: self.total_samples, code, label: label.into() });
+    }
+
+    /// Extract fixed windows around every recorded event matching `event_code` from the
+    /// continuous history of processed samples.
+    pub fn extract_epochs(&self, event_code: u32, config: &EpochConfig) -> Epochs {
+        events::extract_epochs(
+            &self.history,
+            self.history_start_sample,
+            self.config.sample_rate,
+            &self.events,
+            event_code,
+            config,
+        )
+    }
+
+    /// Begin streaming subsequent `process_block` output to an EDF+ or BDF file at `path`.
+    ///
+    /// Errors if a recording is already in progress; call `stop_recording` first. Starting a new
+    /// recording over an active one would abandon the old `Recorder` without flushing its
+    /// trailing record or patching its header's data-record count, leaving a corrupt file.
+    pub fn start_recording(&mut self, path: impl AsRef<Path>, format: RecordingFormat) -> io::Result<()> {
+        if self.recorder.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "a recording is already in progress; call stop_recording first",
+            ));
+        }
+        self.recorder = Some(Recorder::start(path, format, &self.config)?);
+        Ok(())
+    }
+
+    /// Stop recording, flushing any buffered samples and finalizing the file header.
+    pub fn stop_recording(&mut self) -> io::Result<()> {
+        if let Some(recorder) = self.recorder.take() {
+            recorder.stop()?;
+        }
+        Ok(())
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status
+    }
+
+    pub fn config(&self) -> &AdcConfig {
+        &self.config
+    }
+
+    /// Turn a block of raw per-channel ADC samples into a [`ProcessedData`] block: convert to
+    /// physical units, run the FFT to populate `power_spectrums`/`frequency_bins`, then derive
+    /// band powers from those.
+    pub fn process_block(&mut self, timestamp: u64, raw_samples: Vec<Vec<i32>>) -> ProcessedData {
+        let mut processed_voltage_samples: Vec<Vec<f32>> = raw_samples
+            .iter()
+            .map(|channel| channel.iter().map(|&code| self.to_voltage(code)).collect())
+            .collect();
+
+        let bad_channels = dsp::run_pipeline(
+            &mut processed_voltage_samples,
+            self.config.sample_rate as f32,
+            &self.config.pipeline,
+            self.config.montage.as_ref(),
+            &mut self.filter_bank,
+        );
+
+        for (ch, samples) in processed_voltage_samples.iter().enumerate() {
+            if let Some(buffer) = self.history.get_mut(ch) {
+                buffer.extend_from_slice(samples);
+            }
+        }
+        self.total_samples += processed_voltage_samples.first().map_or(0, |c| c.len() as u64);
+        self.evict_old_history();
+
+        let (power_spectrums, frequency_bins) = self.compute_spectrums(&processed_voltage_samples);
+
+        let mut data = ProcessedData {
+            timestamp,
+            raw_samples,
+            processed_voltage_samples,
+            power_spectrums: Some(power_spectrums),
+            frequency_bins: Some(frequency_bins),
+            bad_channels,
+            ..Default::default()
+        };
+
+        data.band_powers = dsp::compute_band_powers(&data, &self.config.dsp);
+
+        if let Some(recorder) = self.recorder.as_mut() {
+            if let Err(err) = recorder.write_block(&data) {
+                data.error = Some(format!("recording write failed: {err}"));
+            }
+        }
+
+        data
+    }
+
+    /// Drop samples older than `history_retention_samples`, advancing `history_start_sample` to
+    /// match so absolute indices into `history` stay correct. Also drops any events that now point
+    /// before the retained window, so `events` stays bounded for long-running acquisitions too.
+    fn evict_old_history(&mut self) {
+        let current_len = self.history.first().map_or(0, Vec::len) as u64;
+        let excess = current_len.saturating_sub(self.history_retention_samples);
+        if excess == 0 {
+            return;
+        }
+
+        for buffer in &mut self.history {
+            buffer.drain(..excess as usize);
+        }
+        self.history_start_sample += excess;
+        self.events.retain(|event| event.sample_index >= self.history_start_sample);
+    }
+
+    fn to_voltage(&self, code: i32) -> f32 {
+        (code as f32 / self.config.gain) * (self.config.vref / i32::MAX as f32)
+    }
+
+    fn compute_spectrums(&mut self, channels: &[Vec<f32>]) -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        let mut power_spectrums = Vec::with_capacity(channels.len());
+        let mut frequency_bins = Vec::with_capacity(channels.len());
+
+        for samples in channels {
+            let n = samples.len();
+            if n == 0 {
+                power_spectrums.push(Vec::new());
+                frequency_bins.push(Vec::new());
+                continue;
+            }
+
+            let fft = self.fft_planner.plan_fft_forward(n);
+            let mut buffer: Vec<Complex<f32>> =
+                samples.iter().map(|&s| Complex::new(s, 0.0)).collect();
+            fft.process(&mut buffer);
+
+            // Single-sided spectrum: bins 0..=n/2 cover 0 Hz up to Nyquist.
+            let half = n / 2 + 1;
+            let power: Vec<f32> = buffer[..half]
+                .iter()
+                .map(|c| (c.re * c.re + c.im * c.im) / n as f32)
+                .collect();
+            let freqs: Vec<f32> = (0..half)
+                .map(|k| k as f32 * self.config.sample_rate as f32 / n as f32)
+                .collect();
+
+            power_spectrums.push(power);
+            frequency_bins.push(freqs);
+        }
+
+        (power_spectrums, frequency_bins)
+    }
+}
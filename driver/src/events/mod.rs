@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+/// A single timestamped marker in the event stream (e.g. a stimulus onset or a button press),
+/// located by absolute sample index rather than wall-clock time so it stays aligned with the
+/// continuous sample stream regardless of processing latency.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventMarker {
+    pub sample_index: u64,
+    pub code: u32,
+    pub label: String,
+}
+
+/// Configuration for extracting fixed windows around matching events.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EpochConfig {
+    /// Window start relative to the event, in ms (e.g. `200.0` for 200 ms before the event).
+    pub pre_ms: f32,
+    /// Window end relative to the event, in ms (e.g. `800.0` for 800 ms after the event).
+    pub post_ms: f32,
+    /// Subtract each channel's mean over the pre-event baseline from every sample in the epoch.
+    pub baseline_correct: bool,
+    /// Reject epochs where any channel's peak-to-peak amplitude exceeds this (in volts).
+    pub reject_peak_to_peak: Option<f32>,
+}
+
+/// Epoched (trial-segmented) data: one fixed-length window per matching, non-rejected event,
+/// baseline-corrected when `EpochConfig::baseline_correct` is set.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Epochs {
+    pub sample_rate: u32,
+    pub pre_ms: f32,
+    pub post_ms: f32,
+    /// `data[trial][channel][sample]`.
+    pub data: Vec<Vec<Vec<f32>>>,
+    /// The event each trial in `data` is locked to, same order and length as `data`.
+    pub events: Vec<EventMarker>,
+}
+
+/// Extract fixed-length epochs from a continuous, per-channel sample buffer around every event
+/// matching `event_code`.
+///
+/// `channels[ch][0]` is the sample at absolute index `first_sample_index`. Events whose window
+/// would run off either edge of `channels` are skipped.
+pub fn extract_epochs(
+    channels: &[Vec<f32>],
+    first_sample_index: u64,
+    sample_rate: u32,
+    events: &[EventMarker],
+    event_code: u32,
+    config: &EpochConfig,
+) -> Epochs {
+    let pre_samples = (config.pre_ms / 1000.0 * sample_rate as f32).round() as i64;
+    let post_samples = (config.post_ms / 1000.0 * sample_rate as f32).round() as i64;
+    let num_samples = channels.first().map_or(0, Vec::len) as i64;
+
+    let mut data = Vec::new();
+    let mut kept_events = Vec::new();
+
+    for event in events.iter().filter(|e| e.code == event_code) {
+        let event_rel = event.sample_index as i64 - first_sample_index as i64;
+        let start = event_rel - pre_samples;
+        let end = event_rel + post_samples;
+        if start < 0 || end > num_samples {
+            continue;
+        }
+
+        let mut trial: Vec<Vec<f32>> = channels
+            .iter()
+            .map(|ch| ch[start as usize..end as usize].to_vec())
+            .collect();
+
+        if config.baseline_correct {
+            let baseline_len = pre_samples.max(0) as usize;
+            if baseline_len > 0 {
+                for ch in trial.iter_mut() {
+                    let baseline_mean: f32 = ch[..baseline_len].iter().sum::<f32>() / baseline_len as f32;
+                    for sample in ch.iter_mut() {
+                        *sample -= baseline_mean;
+                    }
+                }
+            }
+        }
+
+        if let Some(threshold) = config.reject_peak_to_peak {
+            let exceeds_threshold = trial.iter().any(|ch| {
+                let min = ch.iter().cloned().fold(f32::INFINITY, f32::min);
+                let max = ch.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+                (max - min) > threshold
+            });
+            if exceeds_threshold {
+                continue;
+            }
+        }
+
+        data.push(trial);
+        kept_events.push(event.clone());
+    }
+
+    Epochs { sample_rate, pre_ms: config.pre_ms, post_ms: config.post_ms, data, events: kept_events }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 10 Hz sample rate: 1 sample == 100 ms, so pre_ms=200/post_ms=300 are exactly 2/3 samples.
+    const SAMPLE_RATE: u32 = 10;
+
+    fn marker(sample_index: u64) -> EventMarker {
+        EventMarker { sample_index, code: 1, label: "stim".to_string() }
+    }
+
+    fn ramp_channel() -> Vec<f32> {
+        (0..10).map(|i| i as f32).collect()
+    }
+
+    #[test]
+    fn extracts_and_baseline_corrects_a_valid_epoch() {
+        let channels = vec![ramp_channel()];
+        let config =
+            EpochConfig { pre_ms: 200.0, post_ms: 300.0, baseline_correct: true, reject_peak_to_peak: None };
+
+        let epochs = extract_epochs(&channels, 0, SAMPLE_RATE, &[marker(4)], 1, &config);
+
+        assert_eq!(epochs.data.len(), 1);
+        // Raw window is samples[2..7] = [2,3,4,5,6]; baseline mean over the first 2 (pre) samples
+        // is 2.5, so baseline-corrected values are [-0.5, 0.5, 1.5, 2.5, 3.5].
+        assert_eq!(epochs.data[0][0], vec![-0.5, 0.5, 1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn skips_events_whose_window_runs_off_either_edge() {
+        let channels = vec![ramp_channel()];
+        let config =
+            EpochConfig { pre_ms: 200.0, post_ms: 300.0, baseline_correct: false, reject_peak_to_peak: None };
+
+        // event at 1: start = 1 - 2 = -1, runs off the start.
+        // event at 8: end = 8 + 3 = 11 > 10 samples, runs off the end.
+        let epochs = extract_epochs(&channels, 0, SAMPLE_RATE, &[marker(1), marker(8)], 1, &config);
+
+        assert!(epochs.data.is_empty());
+        assert!(epochs.events.is_empty());
+    }
+
+    #[test]
+    fn rejects_epoch_exceeding_peak_to_peak_threshold() {
+        let channels = vec![ramp_channel()];
+        let config = EpochConfig {
+            pre_ms: 200.0,
+            post_ms: 300.0,
+            baseline_correct: false,
+            reject_peak_to_peak: Some(3.0), // window [2..7] has peak-to-peak 4, so this rejects it
+        };
+
+        let epochs = extract_epochs(&channels, 0, SAMPLE_RATE, &[marker(4)], 1, &config);
+
+        assert!(epochs.data.is_empty());
+    }
+
+    #[test]
+    fn ignores_events_with_a_different_code() {
+        let channels = vec![ramp_channel()];
+        let config =
+            EpochConfig { pre_ms: 200.0, post_ms: 300.0, baseline_correct: false, reject_peak_to_peak: None };
+
+        let mut other_code_event = marker(4);
+        other_code_event.code = 2;
+
+        let epochs = extract_epochs(&channels, 0, SAMPLE_RATE, &[other_code_event], 1, &config);
+
+        assert!(epochs.data.is_empty());
+    }
+}
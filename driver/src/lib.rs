@@ -1,6 +1,9 @@
 pub mod board_drivers;
 pub mod dsp;
 pub mod eeg_system;
+pub mod events;
+pub mod montage;
+pub mod recording;
 
 // Re-export the main types that users need
 pub use eeg_system::EegSystem;
@@ -17,6 +20,12 @@ pub struct ProcessedData {
     pub power_spectrums: Option<Vec<Vec<f32>>>,
     /// Optional FFT frequency bins for each channel (should correspond to power_spectrums)
     pub frequency_bins: Option<Vec<Vec<f32>>>,
+    /// Optional per-channel band power breakdown (delta/theta/alpha/beta/gamma), derived from
+    /// `power_spectrums`/`frequency_bins` using the bands configured on `AdcConfig::dsp`.
+    pub band_powers: Option<Vec<dsp::BandPowers>>,
+    /// Indices of channels the preprocessing pipeline's bad-channel detection stage flagged for
+    /// this block (empty if that stage is disabled or found nothing).
+    pub bad_channels: Vec<usize>,
     /// Optional error message if processing failed
     pub error: Option<String>,
 }
@@ -29,6 +38,8 @@ impl Default for ProcessedData {
             processed_voltage_samples: Vec::new(),
             power_spectrums: None,
             frequency_bins: None,
+            band_powers: None,
+            bad_channels: Vec::new(),
             error: None,
         }
     }
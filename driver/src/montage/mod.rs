@@ -0,0 +1,71 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// A single electrode's position in a head-centered 3D coordinate system (as used by EEGLAB
+/// chanlocs files).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ElectrodeLocation {
+    pub label: String,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl ElectrodeLocation {
+    /// This electrode's position projected onto the unit sphere centered on the origin.
+    pub fn unit(&self) -> (f32, f32, f32) {
+        let norm = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        if norm <= f32::EPSILON {
+            (0.0, 0.0, 1.0)
+        } else {
+            (self.x / norm, self.y / norm, self.z / norm)
+        }
+    }
+}
+
+/// Per-channel electrode coordinates for a recording, indexed the same way as `AdcConfig`'s
+/// channels (`electrodes[ch]` is the location of channel `ch`).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Montage {
+    pub electrodes: Vec<ElectrodeLocation>,
+}
+
+impl Montage {
+    /// Load an EEGLAB-style chanlocs list: one `label x y z` per line, whitespace-separated.
+    pub fn load_chanlocs(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut electrodes = Vec::new();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 4 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("expected 'label x y z', got: {line}"),
+                ));
+            }
+
+            let parse_coord = |s: &str| -> io::Result<f32> {
+                s.parse()
+                    .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("bad coordinate: {s}")))
+            };
+
+            electrodes.push(ElectrodeLocation {
+                label: fields[0].to_string(),
+                x: parse_coord(fields[1])?,
+                y: parse_coord(fields[2])?,
+                z: parse_coord(fields[3])?,
+            });
+        }
+
+        Ok(Self { electrodes })
+    }
+}
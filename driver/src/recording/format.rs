@@ -0,0 +1,225 @@
+use std::io::{self, ErrorKind};
+
+/// Which sample-container format a [`super::Recorder`] writes: EDF+ (16-bit signed samples) or
+/// BDF (24-bit signed, BioSemi-style).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordingFormat {
+    Edf,
+    Bdf,
+}
+
+impl RecordingFormat {
+    /// Bytes used to store one sample in a data record.
+    pub fn bytes_per_sample(self) -> usize {
+        match self {
+            RecordingFormat::Edf => 2,
+            RecordingFormat::Bdf => 3,
+        }
+    }
+
+    pub fn digital_min(self) -> i32 {
+        match self {
+            RecordingFormat::Edf => -32768,
+            RecordingFormat::Bdf => -8_388_608,
+        }
+    }
+
+    pub fn digital_max(self) -> i32 {
+        match self {
+            RecordingFormat::Edf => 32767,
+            RecordingFormat::Bdf => 8_388_607,
+        }
+    }
+
+    /// The fixed text EDF/BDF put in the first 8 bytes of the file header.
+    fn version_bytes(self) -> [u8; 8] {
+        match self {
+            RecordingFormat::Edf => *b"0       ",
+            // BDF repurposes byte 0 as 0xFF and bytes 1..8 as "BIOSEMI".
+            RecordingFormat::Bdf => *b"\xffBIOSEMI",
+        }
+    }
+}
+
+/// Per-signal header fields, one instance per recorded channel.
+#[derive(Clone, Debug)]
+pub struct SignalHeader {
+    pub label: String,
+    pub transducer: String,
+    pub physical_dimension: String,
+    pub physical_min: f64,
+    pub physical_max: f64,
+    pub digital_min: i32,
+    pub digital_max: i32,
+    pub prefiltering: String,
+    pub samples_per_record: usize,
+}
+
+/// Fixed-width header shared by the writer and the reader.
+#[derive(Clone, Debug)]
+pub struct FileHeader {
+    pub format: RecordingFormat,
+    pub patient_id: String,
+    pub recording_id: String,
+    pub start_date: String,
+    pub start_time: String,
+    pub num_data_records: i64,
+    pub record_duration_secs: f64,
+    pub signals: Vec<SignalHeader>,
+}
+
+/// Left-justify `value` into exactly `width` ASCII bytes, truncating or space-padding as needed.
+fn ascii_field(value: &str, width: usize) -> Vec<u8> {
+    let mut bytes = value.as_bytes()[..value.len().min(width)].to_vec();
+    bytes.resize(width, b' ');
+    bytes
+}
+
+fn parse_ascii_field(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).trim_end().to_string()
+}
+
+impl FileHeader {
+    pub const RECORD_HEADER_LEN: usize = 256;
+    pub const SIGNAL_HEADER_LEN: usize = 256;
+
+    /// Serialize the fixed record header plus one signal-header block per channel.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let ns = self.signals.len();
+        let mut out = Vec::with_capacity(Self::RECORD_HEADER_LEN + ns * Self::SIGNAL_HEADER_LEN);
+
+        out.extend_from_slice(&self.format.version_bytes());
+        out.extend(ascii_field(&self.patient_id, 80));
+        out.extend(ascii_field(&self.recording_id, 80));
+        out.extend(ascii_field(&self.start_date, 8));
+        out.extend(ascii_field(&self.start_time, 8));
+        out.extend(ascii_field(
+            &((ns + 1) * Self::RECORD_HEADER_LEN).to_string(),
+            8,
+        ));
+        out.extend(ascii_field("", 44));
+        out.extend(ascii_field(&self.num_data_records.to_string(), 8));
+        out.extend(ascii_field(&format!("{}", self.record_duration_secs), 8));
+        out.extend(ascii_field(&ns.to_string(), 4));
+        debug_assert_eq!(out.len(), Self::RECORD_HEADER_LEN);
+
+        // Each field below is itself a contiguous ns * width block, in signal order.
+        for s in &self.signals {
+            out.extend(ascii_field(&s.label, 16));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&s.transducer, 80));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&s.physical_dimension, 8));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&format!("{}", s.physical_min), 8));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&format!("{}", s.physical_max), 8));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&s.digital_min.to_string(), 8));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&s.digital_max.to_string(), 8));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&s.prefiltering, 80));
+        }
+        for s in &self.signals {
+            out.extend(ascii_field(&s.samples_per_record.to_string(), 8));
+        }
+        for _ in &self.signals {
+            out.extend(ascii_field("", 32));
+        }
+
+        out
+    }
+
+    /// Parse the fixed record header plus the per-signal blocks written by [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < Self::RECORD_HEADER_LEN {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "header too short"));
+        }
+
+        let format = match bytes[0] {
+            0xFF => RecordingFormat::Bdf,
+            _ => RecordingFormat::Edf,
+        };
+
+        let mut offset = 8;
+        let take = |off: &mut usize, width: usize| -> &[u8] {
+            let field = &bytes[*off..*off + width];
+            *off += width;
+            field
+        };
+
+        let patient_id = parse_ascii_field(take(&mut offset, 80));
+        let recording_id = parse_ascii_field(take(&mut offset, 80));
+        let start_date = parse_ascii_field(take(&mut offset, 8));
+        let start_time = parse_ascii_field(take(&mut offset, 8));
+        offset += 8; // header byte count, recomputed on write
+        offset += 44; // reserved
+        let num_data_records: i64 = parse_ascii_field(take(&mut offset, 8))
+            .trim()
+            .parse()
+            .unwrap_or(-1);
+        let record_duration_secs: f64 = parse_ascii_field(take(&mut offset, 8))
+            .trim()
+            .parse()
+            .unwrap_or(1.0);
+        let ns: usize = parse_ascii_field(take(&mut offset, 4))
+            .trim()
+            .parse()
+            .map_err(|_| io::Error::new(ErrorKind::InvalidData, "invalid signal count"))?;
+
+        let needed = Self::RECORD_HEADER_LEN + ns * Self::SIGNAL_HEADER_LEN;
+        if bytes.len() < needed {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "signal headers truncated"));
+        }
+
+        let mut field_offset = Self::RECORD_HEADER_LEN;
+        let mut read_field = |width: usize| -> Vec<String> {
+            let block = &bytes[field_offset..field_offset + ns * width];
+            field_offset += ns * width;
+            block.chunks(width).map(parse_ascii_field).collect()
+        };
+
+        let labels = read_field(16);
+        let transducers = read_field(80);
+        let dimensions = read_field(8);
+        let phys_mins = read_field(8);
+        let phys_maxs = read_field(8);
+        let dig_mins = read_field(8);
+        let dig_maxs = read_field(8);
+        let prefilterings = read_field(80);
+        let samples_per_record = read_field(8);
+
+        let signals = (0..ns)
+            .map(|i| SignalHeader {
+                label: labels[i].clone(),
+                transducer: transducers[i].clone(),
+                physical_dimension: dimensions[i].clone(),
+                physical_min: phys_mins[i].trim().parse().unwrap_or(0.0),
+                physical_max: phys_maxs[i].trim().parse().unwrap_or(0.0),
+                digital_min: dig_mins[i].trim().parse().unwrap_or(format.digital_min()),
+                digital_max: dig_maxs[i].trim().parse().unwrap_or(format.digital_max()),
+                prefiltering: prefilterings[i].clone(),
+                samples_per_record: samples_per_record[i].trim().parse().unwrap_or(0),
+            })
+            .collect();
+
+        Ok(Self {
+            format,
+            patient_id,
+            recording_id,
+            start_date,
+            start_time,
+            num_data_records,
+            record_duration_secs,
+            signals,
+        })
+    }
+}
@@ -0,0 +1,61 @@
+mod format;
+mod reader;
+mod writer;
+
+pub use format::{FileHeader, RecordingFormat, SignalHeader};
+pub use reader::{read, RecordedFile};
+pub use writer::Recorder;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board_drivers::AdcConfig;
+    use crate::ProcessedData;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("elata_eeg_recording_test_{name}_{:?}", std::thread::current().id()));
+        path
+    }
+
+    fn round_trip(format: RecordingFormat, path: &std::path::Path) {
+        let config = AdcConfig { channels: 2, sample_rate: 4, ..AdcConfig::default() };
+        let samples = vec![
+            vec![0.0_f32, 0.00001, -0.00001, 0.00002],
+            vec![-0.00002_f32, 0.0, 0.00001, -0.00001],
+        ];
+
+        let mut recorder = Recorder::start(path, format, &config).expect("start recorder");
+        let block = ProcessedData { processed_voltage_samples: samples.clone(), ..Default::default() };
+        recorder.write_block(&block).expect("write block");
+        recorder.stop().expect("stop recorder");
+
+        let recorded = read(path).expect("read back recording");
+        std::fs::remove_file(path).ok();
+
+        assert_eq!(recorded.channels.len(), samples.len());
+        // One data record's worth of 16-bit (EDF) / 24-bit (BDF) quantization error, in uV.
+        const TOLERANCE_UV: f32 = 10.0;
+        for (ch, expected) in samples.iter().enumerate() {
+            assert_eq!(recorded.channels[ch].len(), expected.len());
+            for (i, &volts) in expected.iter().enumerate() {
+                let expected_uv = volts * 1_000_000.0;
+                let got_uv = recorded.channels[ch][i];
+                assert!(
+                    (got_uv - expected_uv).abs() < TOLERANCE_UV,
+                    "channel {ch} sample {i}: expected ~{expected_uv} uV, got {got_uv} uV"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn edf_round_trip_preserves_samples_within_quantization() {
+        round_trip(RecordingFormat::Edf, &temp_path("edf"));
+    }
+
+    #[test]
+    fn bdf_round_trip_preserves_samples_within_quantization() {
+        round_trip(RecordingFormat::Bdf, &temp_path("bdf"));
+    }
+}
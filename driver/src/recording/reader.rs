@@ -0,0 +1,64 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::format::{FileHeader, RecordingFormat};
+
+/// An EDF+/BDF file read back into memory: header metadata plus one `Vec<f32>` of physical-unit
+/// samples per channel, in original recording order.
+pub struct RecordedFile {
+    pub header: FileHeader,
+    pub channels: Vec<Vec<f32>>,
+}
+
+/// Read and fully decode an EDF+ or BDF file written by [`super::Recorder`].
+pub fn read(path: impl AsRef<Path>) -> io::Result<RecordedFile> {
+    let bytes = fs::read(path)?;
+    let header = FileHeader::from_bytes(&bytes)?;
+
+    let ns = header.signals.len();
+    let header_len = FileHeader::RECORD_HEADER_LEN + ns * FileHeader::SIGNAL_HEADER_LEN;
+    let bytes_per_sample = header.format.bytes_per_sample();
+
+    let mut channels: Vec<Vec<f32>> = vec![Vec::new(); ns];
+    let mut offset = header_len;
+
+    while offset < bytes.len() {
+        for (ch, signal) in header.signals.iter().enumerate() {
+            let span_phys = signal.physical_max - signal.physical_min;
+            let span_dig = (signal.digital_max - signal.digital_min) as f64;
+
+            for _ in 0..signal.samples_per_record {
+                if offset + bytes_per_sample > bytes.len() {
+                    break;
+                }
+                let digital = decode_sample(header.format, &bytes[offset..offset + bytes_per_sample]);
+                offset += bytes_per_sample;
+
+                let physical = if span_dig == 0.0 {
+                    0.0
+                } else {
+                    (digital as f64 - signal.digital_min as f64) / span_dig * span_phys
+                        + signal.physical_min
+                };
+                channels[ch].push(physical as f32);
+            }
+        }
+    }
+
+    Ok(RecordedFile { header, channels })
+}
+
+fn decode_sample(format: RecordingFormat, bytes: &[u8]) -> i32 {
+    match format {
+        RecordingFormat::Edf => i16::from_le_bytes([bytes[0], bytes[1]]) as i32,
+        RecordingFormat::Bdf => {
+            let mut word = [bytes[0], bytes[1], bytes[2], 0];
+            // Sign-extend the 24-bit little-endian sample into the top byte.
+            if bytes[2] & 0x80 != 0 {
+                word[3] = 0xFF;
+            }
+            i32::from_le_bytes(word)
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::board_drivers::AdcConfig;
+use crate::ProcessedData;
+
+use super::format::{FileHeader, RecordingFormat, SignalHeader};
+
+/// Streams [`ProcessedData`] blocks to an EDF+ or BDF file on disk.
+///
+/// Samples are buffered per channel until a full data record (`record_duration_secs` worth of
+/// samples at `AdcConfig::sample_rate`) is available, then flushed as one interleaved record.
+pub struct Recorder {
+    file: File,
+    format: RecordingFormat,
+    samples_per_record: usize,
+    physical_min: f64,
+    physical_max: f64,
+    digital_min: i32,
+    digital_max: i32,
+    channel_buffers: Vec<Vec<f32>>,
+    records_written: u64,
+}
+
+impl Recorder {
+    pub fn start(path: impl AsRef<Path>, format: RecordingFormat, config: &AdcConfig) -> io::Result<Self> {
+        let record_duration_secs = 1.0;
+        let samples_per_record = config.sample_rate as usize;
+
+        // Physical range derived from the ADC's reference voltage and gain, in microvolts.
+        let physical_max = (config.vref / config.gain) as f64 * 1_000_000.0;
+        let physical_min = -physical_max;
+        let digital_min = format.digital_min();
+        let digital_max = format.digital_max();
+
+        let signals = (0..config.channels)
+            .map(|ch| SignalHeader {
+                label: format!("EEG Ch{ch}"),
+                transducer: "AgAgCl electrode".to_string(),
+                physical_dimension: "uV".to_string(),
+                physical_min,
+                physical_max,
+                digital_min,
+                digital_max,
+                prefiltering: "HP:0.5Hz LP:100Hz".to_string(),
+                samples_per_record,
+            })
+            .collect();
+
+        let header = FileHeader {
+            format,
+            patient_id: "X X X X".to_string(),
+            recording_id: "Startdate X X X X".to_string(),
+            start_date: "01.01.00".to_string(),
+            start_time: "00.00.00".to_string(),
+            num_data_records: -1, // patched in on `stop`
+            record_duration_secs,
+            signals,
+        };
+
+        let mut file = File::create(path)?;
+        file.write_all(&header.to_bytes())?;
+
+        Ok(Self {
+            file,
+            format,
+            samples_per_record,
+            physical_min,
+            physical_max,
+            digital_min,
+            digital_max,
+            channel_buffers: vec![Vec::new(); config.channels],
+            records_written: 0,
+        })
+    }
+
+    /// Append a processed block's voltage samples, flushing any full data records they complete.
+    pub fn write_block(&mut self, data: &ProcessedData) -> io::Result<()> {
+        for (ch, samples) in data.processed_voltage_samples.iter().enumerate() {
+            if let Some(buffer) = self.channel_buffers.get_mut(ch) {
+                buffer.extend(samples.iter().map(|v| v * 1_000_000.0)); // volts -> microvolts
+            }
+        }
+        self.flush_full_records()
+    }
+
+    fn flush_full_records(&mut self) -> io::Result<()> {
+        // Copied out so the encode step below doesn't need to borrow `self` while
+        // `self.channel_buffers` is already mutably borrowed by the `for` loop.
+        let format = self.format;
+        let physical_min = self.physical_min;
+        let physical_max = self.physical_max;
+        let digital_min = self.digital_min;
+        let digital_max = self.digital_max;
+
+        while self
+            .channel_buffers
+            .iter()
+            .all(|b| b.len() >= self.samples_per_record)
+        {
+            let mut record_bytes =
+                Vec::with_capacity(self.channel_buffers.len() * self.samples_per_record * format.bytes_per_sample());
+            for buffer in &mut self.channel_buffers {
+                let record: Vec<f32> = buffer.drain(..self.samples_per_record).collect();
+                for sample in record {
+                    encode_sample(sample, format, physical_min, physical_max, digital_min, digital_max, &mut record_bytes);
+                }
+            }
+            self.file.write_all(&record_bytes)?;
+            self.records_written += 1;
+        }
+        Ok(())
+    }
+
+    /// Flush any partial trailing record (zero-padded) and patch the header's data-record count.
+    pub fn stop(mut self) -> io::Result<()> {
+        if self.channel_buffers.iter().any(|b| !b.is_empty()) {
+            for buffer in &mut self.channel_buffers {
+                buffer.resize(self.samples_per_record, 0.0);
+            }
+            self.flush_full_records()?;
+        }
+
+        let records_field = format!("{:<8}", self.records_written);
+        // Offset of the "number of data records" field within the fixed 256-byte header.
+        const NUM_RECORDS_OFFSET: u64 = 8 + 80 + 80 + 8 + 8 + 8 + 44;
+        self.file.seek(SeekFrom::Start(NUM_RECORDS_OFFSET))?;
+        self.file.write_all(records_field.as_bytes())?;
+        self.file.flush()
+    }
+}
+
+/// Scale a physical-unit sample into the format's digital range and append its little-endian
+/// bytes to `out`. A free function (rather than a `&self` method) so `flush_full_records` can call
+/// it while `self.channel_buffers` is already mutably borrowed.
+fn encode_sample(
+    physical: f32,
+    format: RecordingFormat,
+    physical_min: f64,
+    physical_max: f64,
+    digital_min: i32,
+    digital_max: i32,
+    out: &mut Vec<u8>,
+) {
+    let physical = physical.clamp(physical_min as f32, physical_max as f32) as f64;
+    let span_phys = physical_max - physical_min;
+    let span_dig = (digital_max - digital_min) as f64;
+    let scaled = if span_phys == 0.0 {
+        0.0
+    } else {
+        (physical - physical_min) / span_phys * span_dig + digital_min as f64
+    };
+    let digital = scaled.round().clamp(digital_min as f64, digital_max as f64) as i32;
+
+    match format {
+        RecordingFormat::Edf => out.extend_from_slice(&(digital as i16).to_le_bytes()),
+        RecordingFormat::Bdf => {
+            let bytes = digital.to_le_bytes();
+            out.extend_from_slice(&bytes[..3]);
+        }
+    }
+}